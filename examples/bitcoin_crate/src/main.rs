@@ -4,13 +4,15 @@ extern crate serde_derive;
 extern crate serde_json;
 
 use bitcoin::{
-    absolute::LockTime, transaction, Amount, OutPoint, ScriptBuf, Sequence, Transaction, TxIn,
-    TxOut, Txid, Witness,
+    absolute::LockTime, transaction, Amount, FeeRate, OutPoint, ScriptBuf, Sequence, Transaction,
+    TxIn, TxOut, Txid, Witness,
 };
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rust_coinselect::{
+    algorithms::srd::select_coin_srd,
     selectcoin::select_coin,
-    types::{CoinSelectionOpt, ExcessStrategy, OutputGroup},
+    types::{CoinSelectionOpt, ExcessStrategy, OutputGroup, SelectionAlgorithm},
 };
 use serde_derive::Deserialize;
 use std::fs;
@@ -117,10 +119,12 @@ fn create_outputgroup(
             }
         }
         output_group_vec.push(OutputGroup {
-            value: tx.output.iter().map(|op| op.value.to_sat()).sum(),
+            value: tx.output.iter().map(|op| op.value).sum(),
             weight: tx.total_size() as u32,
             input_count: tx.input.len(),
             creation_sequence: Some(creation_sequence),
+            // Ordinary UTXOs; flag rare-sat / inscription outputs here to keep them unspent.
+            is_protected: false,
         })
     }
 
@@ -132,25 +136,29 @@ fn create_select_options() -> Result<Vec<CoinSelectionOpt>, Box<dyn std::error::
     let mut coin_select_options_vec: Vec<CoinSelectionOpt> = Vec::new();
     // Creating 5 different options for coin selection
     for _ in 0..5 {
-        // Random selection of Excess Strategy
-        let excess_strategy = match rng.gen_range(0..3) {
+        // Random selection of Excess Strategy; Sweep drains the whole balance
+        // (minus fees) to the recipient with no change output.
+        let excess_strategy = match rng.gen_range(0..4) {
             0 => ExcessStrategy::ToChange,
             1 => ExcessStrategy::ToFee,
             2 => ExcessStrategy::ToRecipient,
+            3 => ExcessStrategy::Sweep,
             _ => unreachable!(),
         };
         coin_select_options_vec.push(CoinSelectionOpt {
-            target_value: rng.gen_range(40000..5000000000i64) as u64,
-            target_feerate: rng.gen_range(1.0..5.0) as f32,
-            long_term_feerate: Some(rng.gen_range(1..10) as f32),
-            min_absolute_fee: rng.gen_range(1..20) as u64,
+            target_value: Amount::from_sat(rng.gen_range(40000..5000000000u64)),
+            target_feerate: FeeRate::from_sat_per_vb(rng.gen_range(1..5)).expect("valid feerate"),
+            long_term_feerate: FeeRate::from_sat_per_vb(rng.gen_range(1..10)),
+            min_absolute_fee: Amount::from_sat(rng.gen_range(1..20)),
             base_weight: rng.gen_range(1..30) as u32,
             change_weight: rng.gen_range(5..30) as u32,
-            change_cost: rng.gen_range(1..20) as u64,
+            change_cost: Amount::from_sat(rng.gen_range(1..20)),
             avg_input_weight: rng.gen_range(1..10) as u32,
             avg_output_weight: rng.gen_range(1..10) as u32,
-            min_change_value: rng.gen_range(100..1000) as u64,
+            min_change_value: Amount::from_sat(rng.gen_range(100..1000)),
             excess_strategy,
+            // Fall back to single-random-draw when Branch-and-Bound finds no match.
+            fallback: SelectionAlgorithm::SingleRandomDraw,
         })
     }
     Ok(coin_select_options_vec)
@@ -161,7 +169,7 @@ fn perform_select_coin(utxos: Vec<OutputGroup>, coin_select_options_vec: Vec<Coi
     println!("\nThe total number of UTXOs available: {:?}", utxos.len());
     for (i, utxo) in utxos.iter().enumerate() {
         println!("\nUTXO #:{}", i);
-        println!("\nValue:{} sats", utxo.value);
+        println!("\nValue:{} sats", utxo.value.to_sat());
         println!("Weight:{} bytes", utxo.weight);
         println!("No. of Inputs: {}", utxo.input_count);
         println!(
@@ -170,12 +178,15 @@ fn perform_select_coin(utxos: Vec<OutputGroup>, coin_select_options_vec: Vec<Coi
         );
     }
 
+    // Seed the RNG so selections are deterministic and can be recorded/replayed.
+    let mut rng = StdRng::seed_from_u64(0);
+
     for (_, coin_select_options) in coin_select_options_vec.iter().enumerate().take(5) {
         println!(
             "\nSelecting UTXOs to total: {:?} sats",
             coin_select_options.target_value
         );
-        match select_coin(&utxos, &coin_select_options) {
+        match select_coin(&utxos, coin_select_options, &mut rng) {
             Ok(selectionoutput) => {
                 println!(
                     "Selected utxo index and waste metrics are: {:?}",
@@ -186,6 +197,17 @@ fn perform_select_coin(utxos: Vec<OutputGroup>, coin_select_options_vec: Vec<Coi
                 println!("Error performing coin selection: {:?}", e);
             }
         }
+
+        // Run single-random-draw directly so its waste metrics can be compared
+        // against the dispatched algorithm on the same UTXO set.
+        match select_coin_srd(&utxos, coin_select_options, &mut rng) {
+            Ok(selectionoutput) => {
+                println!("Single-random-draw selection: {:?}", selectionoutput);
+            }
+            Err(e) => {
+                println!("Error performing single-random-draw: {:?}", e);
+            }
+        }
     }
 }
 
@@ -0,0 +1,148 @@
+//! Branch-and-bound coin selection.
+
+use bitcoin::Amount;
+use rand::RngCore;
+
+use crate::algorithms::srd::select_coin_srd;
+use crate::types::{
+    CoinSelectionOpt, OutputGroup, SelectionAlgorithm, SelectionError, SelectionOutput,
+    WasteMetric,
+};
+use crate::utils::{calculate_fee, calculate_waste, effective_value};
+
+/// Select coins with branch-and-bound, delegating to a fallback on failure.
+///
+/// The search is a depth-first traversal over UTXOs ordered by descending
+/// effective value, branching on include/exclude at each step. A branch is
+/// pruned when its accumulated value overshoots `target + change_cost`, or when
+/// even taking every remaining UTXO could not reach the target. If the search
+/// exhausts without a match, the selector named by
+/// [`CoinSelectionOpt::fallback`] is run over the same UTXO set; a fallback of
+/// [`SelectionAlgorithm::BranchAndBound`] runs no second search and returns
+/// [`SelectionError::InsufficientFunds`].
+pub fn select_coin_bnb(
+    inputs: &[OutputGroup],
+    options: &CoinSelectionOpt,
+    rng: &mut impl RngCore,
+) -> Result<SelectionOutput, SelectionError> {
+    let effective_values: Vec<i64> = inputs
+        .iter()
+        .map(|utxo| effective_value(utxo, options.target_feerate))
+        .collect();
+
+    // The available-balance check counts every group (protected included);
+    // protected groups are only kept out of the selectable `order` below.
+    if available_value(&effective_values) < options.target_value.to_sat() as i64 {
+        return Err(SelectionError::InsufficientFunds);
+    }
+
+    // Search spendable, positive-effective-value UTXOs in descending order;
+    // protected groups are excluded from the candidate pool.
+    let mut order: Vec<usize> = (0..inputs.len())
+        .filter(|index| !inputs[*index].is_protected && effective_values[*index] > 0)
+        .collect();
+    order.sort_by(|a, b| effective_values[*b].cmp(&effective_values[*a]));
+
+    let upper_bound = options.target_value + options.change_cost;
+    let mut selection = Vec::new();
+    if let Some(selected) = search(
+        &effective_values,
+        &order,
+        0,
+        0,
+        options.target_value.to_sat() as i64,
+        upper_bound.to_sat() as i64,
+        &mut selection,
+    ) {
+        return Ok(build_output(inputs, options, &selected));
+    }
+
+    // No exact match: hand off to the configured fallback algorithm.
+    match options.fallback {
+        SelectionAlgorithm::SingleRandomDraw => select_coin_srd(inputs, options, rng),
+        SelectionAlgorithm::BranchAndBound => Err(SelectionError::InsufficientFunds),
+    }
+}
+
+/// Sum of positive effective values over every group, used for the
+/// available-balance (insufficient-funds) check.
+fn available_value(effective_values: &[i64]) -> i64 {
+    effective_values.iter().filter(|value| **value > 0).sum()
+}
+
+/// Depth-first include/exclude search; returns the first selection whose
+/// effective value lands in `[target, upper_bound]`.
+#[allow(clippy::too_many_arguments)]
+fn search(
+    effective_values: &[i64],
+    order: &[usize],
+    position: usize,
+    accumulated: i64,
+    target: i64,
+    upper_bound: i64,
+    selection: &mut Vec<usize>,
+) -> Option<Vec<usize>> {
+    if accumulated > upper_bound {
+        return None;
+    }
+    if accumulated >= target {
+        return Some(selection.clone());
+    }
+    if position >= order.len() {
+        return None;
+    }
+
+    // Prune: even taking every remaining UTXO cannot reach the target.
+    let remaining: i64 = order[position..]
+        .iter()
+        .map(|index| effective_values[*index])
+        .sum();
+    if accumulated + remaining < target {
+        return None;
+    }
+
+    let index = order[position];
+
+    // Branch: include the current UTXO.
+    selection.push(index);
+    if let Some(found) = search(
+        effective_values,
+        order,
+        position + 1,
+        accumulated + effective_values[index],
+        target,
+        upper_bound,
+        selection,
+    ) {
+        return Some(found);
+    }
+    selection.pop();
+
+    // Branch: exclude the current UTXO.
+    search(
+        effective_values,
+        order,
+        position + 1,
+        accumulated,
+        target,
+        upper_bound,
+        selection,
+    )
+}
+
+fn build_output(
+    inputs: &[OutputGroup],
+    options: &CoinSelectionOpt,
+    selected_inputs: &[usize],
+) -> SelectionOutput {
+    let accumulated_value: Amount = selected_inputs.iter().map(|index| inputs[*index].value).sum();
+    let selected_weight: u32 =
+        options.base_weight + selected_inputs.iter().map(|index| inputs[*index].weight).sum::<u32>();
+    let estimated_fee = calculate_fee(selected_weight, options.target_feerate);
+    let waste = calculate_waste(options, selected_weight, accumulated_value, estimated_fee);
+    SelectionOutput {
+        selected_inputs: selected_inputs.to_vec(),
+        waste: WasteMetric(waste),
+        max_sendable: None,
+    }
+}
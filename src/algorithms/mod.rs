@@ -0,0 +1,4 @@
+//! The individual coin selection algorithms.
+
+pub mod bnb;
+pub mod srd;
@@ -0,0 +1,78 @@
+//! Single-random-draw coin selection.
+
+use bitcoin::Amount;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+
+use crate::types::{CoinSelectionOpt, OutputGroup, SelectionError, SelectionOutput, WasteMetric};
+use crate::utils::{calculate_fee, calculate_waste, effective_value};
+
+/// Select coins by single random draw.
+///
+/// A standalone algorithm, parallel to [`select_coin_bnb`](crate::algorithms::bnb::select_coin_bnb)
+/// and usable directly as its fallback: the non-protected candidates are
+/// shuffled uniformly at random with `rng`, then accumulated in shuffled order
+/// until their value covers `target_value + min_change_value` (plus the fee).
+/// The accumulated prefix is returned with the same [`SelectionOutput`] waste
+/// metrics the other algorithms report.
+pub fn select_coin_srd(
+    inputs: &[OutputGroup],
+    options: &CoinSelectionOpt,
+    rng: &mut impl RngCore,
+) -> Result<SelectionOutput, SelectionError> {
+    // The available-balance check counts every group the wallet holds
+    // (protected included); protected groups are only kept out of the
+    // shuffled candidate pool below.
+    let available: i64 = inputs
+        .iter()
+        .map(|utxo| effective_value(utxo, options.target_feerate))
+        .filter(|value| *value > 0)
+        .sum();
+    if available < options.target_value.to_sat() as i64 {
+        return Err(SelectionError::InsufficientFunds);
+    }
+
+    let mut order: Vec<usize> = (0..inputs.len())
+        .filter(|index| !inputs[*index].is_protected)
+        .collect();
+    order.shuffle(rng);
+
+    // Stop once the prefix covers the target plus a viable change output; if the
+    // spendable balance never reaches that threshold, the full prefix is returned
+    // as long as its effective value still covers the target.
+    let threshold = options.target_value + options.min_change_value;
+    let mut selected_inputs = Vec::new();
+    let mut accumulated_value = Amount::ZERO;
+    let mut selected_effective: i64 = 0;
+    let mut selected_weight = options.base_weight;
+    for index in order {
+        let utxo = &inputs[index];
+        let utxo_effective = effective_value(utxo, options.target_feerate);
+        if utxo_effective <= 0 {
+            continue;
+        }
+        selected_effective += utxo_effective;
+        selected_inputs.push(index);
+        accumulated_value += utxo.value;
+        selected_weight += utxo.weight;
+
+        let estimated_fee = calculate_fee(selected_weight, options.target_feerate);
+        if accumulated_value >= threshold + estimated_fee {
+            break;
+        }
+    }
+
+    let estimated_fee = calculate_fee(selected_weight, options.target_feerate);
+    let reached_threshold = accumulated_value >= threshold + estimated_fee;
+    if selected_inputs.is_empty()
+        || (!reached_threshold && selected_effective < options.target_value.to_sat() as i64)
+    {
+        return Err(SelectionError::InsufficientFunds);
+    }
+    let waste = calculate_waste(options, selected_weight, accumulated_value, estimated_fee);
+    Ok(SelectionOutput {
+        selected_inputs,
+        waste: WasteMetric(waste),
+        max_sendable: None,
+    })
+}
@@ -0,0 +1,12 @@
+//! A blockchain-agnostic coin selection library.
+//!
+//! The public entry point is [`selectcoin::select_coin`], which dispatches to
+//! one of the algorithms in [`algorithms`], or drains every spendable UTXO
+//! itself in sweep mode. Every randomized algorithm takes a
+//! caller-supplied [`rand::RngCore`] so that selection can be seeded and
+//! replayed deterministically.
+
+pub mod algorithms;
+pub mod selectcoin;
+pub mod types;
+pub mod utils;
@@ -0,0 +1,169 @@
+//! The public coin selection entry point.
+
+use bitcoin::Amount;
+use rand::RngCore;
+
+use crate::algorithms::bnb::select_coin_bnb;
+use crate::types::{
+    CoinSelectionOpt, ExcessStrategy, OutputGroup, SelectionError, SelectionOutput, WasteMetric,
+};
+use crate::utils::{calculate_fee, calculate_waste};
+
+/// Select coins for the spend described by `options`.
+///
+/// In [`ExcessStrategy::Sweep`] mode the whole balance is drained (see
+/// [`select_coin_sweep`]). Otherwise branch-and-bound drives the selection and
+/// delegates to the configured [`CoinSelectionOpt::fallback`] when it finds no
+/// exact match. With the single-random-draw fallback a selection is returned
+/// whenever the spendable balance covers the target; with
+/// [`SelectionAlgorithm::BranchAndBound`](crate::types::SelectionAlgorithm) as
+/// the fallback no second search runs and a failed search returns
+/// [`SelectionError::InsufficientFunds`].
+/// `rng` seeds the randomized algorithms so callers can reproduce a selection
+/// exactly.
+pub fn select_coin(
+    inputs: &[OutputGroup],
+    options: &CoinSelectionOpt,
+    rng: &mut impl RngCore,
+) -> Result<SelectionOutput, SelectionError> {
+    match options.excess_strategy {
+        ExcessStrategy::Sweep => select_coin_sweep(inputs, options),
+        _ => select_coin_bnb(inputs, options, rng),
+    }
+}
+
+/// Drain every non-protected UTXO, sending the whole balance minus fees to the
+/// recipient with no change output.
+///
+/// The maximum sendable amount is the total non-protected value minus the fee
+/// for the base weight plus those inputs at `target_feerate`; selection fails
+/// when no positive amount is left after that fee.
+pub fn select_coin_sweep(
+    inputs: &[OutputGroup],
+    options: &CoinSelectionOpt,
+) -> Result<SelectionOutput, SelectionError> {
+    let selected_inputs: Vec<usize> = (0..inputs.len())
+        .filter(|index| !inputs[*index].is_protected)
+        .collect();
+
+    let accumulated_value: Amount = selected_inputs
+        .iter()
+        .map(|index| inputs[*index].value)
+        .sum();
+    let selected_weight: u32 = options.base_weight
+        + selected_inputs
+            .iter()
+            .map(|index| inputs[*index].weight)
+            .sum::<u32>();
+
+    let fee = calculate_fee(selected_weight, options.target_feerate);
+    // Maximum sendable amount; the sweep is only viable if it leaves a positive
+    // amount for the recipient after fees.
+    let max_sendable = accumulated_value.checked_sub(fee);
+    let max_sendable = match max_sendable {
+        Some(amount) if amount > Amount::ZERO && !selected_inputs.is_empty() => amount,
+        _ => return Err(SelectionError::InsufficientFunds),
+    };
+
+    let waste = calculate_waste(options, selected_weight, accumulated_value, fee);
+    Ok(SelectionOutput {
+        selected_inputs,
+        waste: WasteMetric(waste),
+        max_sendable: Some(max_sendable),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::srd::select_coin_srd;
+    use crate::types::SelectionAlgorithm;
+    use bitcoin::FeeRate;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn make_utxo(value: u64, weight: u32) -> OutputGroup {
+        OutputGroup {
+            value: Amount::from_sat(value),
+            weight,
+            input_count: 1,
+            creation_sequence: None,
+            is_protected: false,
+        }
+    }
+
+    fn make_opt(target: u64, feerate: FeeRate, excess_strategy: ExcessStrategy) -> CoinSelectionOpt {
+        CoinSelectionOpt {
+            target_value: Amount::from_sat(target),
+            target_feerate: feerate,
+            long_term_feerate: Some(feerate),
+            min_absolute_fee: Amount::ZERO,
+            base_weight: 0,
+            change_weight: 0,
+            change_cost: Amount::ZERO,
+            avg_input_weight: 0,
+            avg_output_weight: 0,
+            min_change_value: Amount::ZERO,
+            excess_strategy,
+            fallback: SelectionAlgorithm::SingleRandomDraw,
+        }
+    }
+
+    fn selected_value(inputs: &[OutputGroup], output: &SelectionOutput) -> Amount {
+        output
+            .selected_inputs
+            .iter()
+            .map(|index| inputs[*index].value)
+            .sum()
+    }
+
+    #[test]
+    fn bnb_finds_exact_in_range_subset() {
+        let utxos = [make_utxo(3, 0), make_utxo(2, 0), make_utxo(1, 0)];
+        let opt = make_opt(5, FeeRate::ZERO, ExcessStrategy::ToChange);
+        let mut rng = StdRng::seed_from_u64(0);
+        let output = select_coin_bnb(&utxos, &opt, &mut rng).expect("a subset sums to the target");
+        // Change cost is zero, so only an exact-match subset is accepted.
+        assert_eq!(selected_value(&utxos, &output), Amount::from_sat(5));
+    }
+
+    #[test]
+    fn bnb_falls_back_to_srd_on_no_match() {
+        // No subset of {4, 4} sums into the exact window [5, 5].
+        let utxos = [make_utxo(4, 0), make_utxo(4, 0)];
+        let opt = make_opt(5, FeeRate::ZERO, ExcessStrategy::ToChange);
+        let mut rng = StdRng::seed_from_u64(0);
+        let output = select_coin_bnb(&utxos, &opt, &mut rng).expect("falls back to single random draw");
+        assert!(selected_value(&utxos, &output) >= Amount::from_sat(5));
+    }
+
+    #[test]
+    fn srd_is_deterministic_under_a_seeded_rng() {
+        let utxos = [make_utxo(5, 0), make_utxo(5, 0), make_utxo(5, 0), make_utxo(5, 0)];
+        let opt = make_opt(6, FeeRate::ZERO, ExcessStrategy::ToChange);
+        let first = select_coin_srd(&utxos, &opt, &mut StdRng::seed_from_u64(42)).unwrap();
+        let second = select_coin_srd(&utxos, &opt, &mut StdRng::seed_from_u64(42)).unwrap();
+        assert_eq!(first.selected_inputs, second.selected_inputs);
+    }
+
+    #[test]
+    fn sweep_rejects_a_dust_only_set() {
+        // The single UTXO is worth less than the fee to spend it.
+        let utxos = [make_utxo(1, 1000)];
+        let feerate = FeeRate::from_sat_per_vb(1).unwrap();
+        let opt = make_opt(0, feerate, ExcessStrategy::Sweep);
+        assert_eq!(
+            select_coin_sweep(&utxos, &opt),
+            Err(SelectionError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn sweep_reports_max_sendable() {
+        let utxos = [make_utxo(100, 0), make_utxo(200, 0)];
+        let opt = make_opt(0, FeeRate::ZERO, ExcessStrategy::Sweep);
+        let output = select_coin_sweep(&utxos, &opt).expect("positive balance");
+        assert_eq!(output.selected_inputs, vec![0, 1]);
+        assert_eq!(output.max_sendable, Some(Amount::from_sat(300)));
+    }
+}
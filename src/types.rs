@@ -0,0 +1,98 @@
+//! Core data types shared across the selection algorithms.
+
+use bitcoin::{Amount, FeeRate};
+
+/// A spendable group of one or more UTXOs presented to coin selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputGroup {
+    /// Total value of the group.
+    pub value: Amount,
+    /// Total weight of the group's inputs, in weight units.
+    pub weight: u32,
+    /// Number of inputs spent by the group.
+    pub input_count: usize,
+    /// Relative creation order, used to break ties and express spend priority.
+    pub creation_sequence: Option<u32>,
+    /// When set, the group is excluded from selection (e.g. it holds rare sats
+    /// or an inscription the wallet wants to keep).
+    pub is_protected: bool,
+}
+
+/// How any value in excess of the target (after fees) should be handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExcessStrategy {
+    /// Drop the excess into the transaction fee.
+    ToFee,
+    /// Add the excess to the recipient output.
+    ToRecipient,
+    /// Emit a change output for the excess.
+    ToChange,
+    /// Drain every non-protected UTXO, sending the whole balance minus fees to
+    /// the recipient with no change output.
+    Sweep,
+}
+
+/// A coin selection algorithm, used to name the branch-and-bound fallback in
+/// [`CoinSelectionOpt::fallback`]; [`select_coin`](crate::selectcoin::select_coin)
+/// does not read it to choose its first algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionAlgorithm {
+    /// Deterministic branch-and-bound search.
+    BranchAndBound,
+    /// Randomized single-random-draw accumulation.
+    SingleRandomDraw,
+}
+
+/// Parameters that describe the spend coin selection is solving for.
+#[derive(Debug, Clone, Copy)]
+pub struct CoinSelectionOpt {
+    /// Amount the recipient should receive.
+    pub target_value: Amount,
+    /// Fee rate the transaction is being built at.
+    pub target_feerate: FeeRate,
+    /// Long-term fee rate used to estimate the cost of spending change later.
+    pub long_term_feerate: Option<FeeRate>,
+    /// Minimum absolute fee the transaction must pay.
+    pub min_absolute_fee: Amount,
+    /// Weight of the transaction before any inputs are added.
+    pub base_weight: u32,
+    /// Weight contributed by a change output.
+    pub change_weight: u32,
+    /// Cost of creating and later spending a change output.
+    pub change_cost: Amount,
+    /// Average weight of a single input, used for estimation.
+    pub avg_input_weight: u32,
+    /// Average weight of a single output, used for estimation.
+    pub avg_output_weight: u32,
+    /// Smallest change output worth creating.
+    pub min_change_value: Amount,
+    /// How excess value is handled once a selection is found.
+    pub excess_strategy: ExcessStrategy,
+    /// Algorithm branch-and-bound delegates to when it finds no exact match.
+    pub fallback: SelectionAlgorithm,
+}
+
+/// The result of a successful selection: the chosen inputs and their waste.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionOutput {
+    /// Indices into the input slice that were selected.
+    pub selected_inputs: Vec<usize>,
+    /// The waste metric for this selection, lower is better.
+    pub waste: WasteMetric,
+    /// For a [`ExcessStrategy::Sweep`] selection, the maximum amount sendable to
+    /// the recipient (selected value minus fees). `None` for targeted spends.
+    pub max_sendable: Option<Amount>,
+}
+
+/// The waste metric of a selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasteMetric(pub Amount);
+
+/// Reasons coin selection can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionError {
+    /// The available balance cannot cover the target plus fees. Also returned
+    /// when branch-and-bound finds no match and its fallback is
+    /// branch-and-bound itself.
+    InsufficientFunds,
+}
@@ -0,0 +1,52 @@
+//! Fee and waste arithmetic shared by the selection algorithms.
+
+use bitcoin::{Amount, FeeRate, Weight};
+
+use crate::types::{CoinSelectionOpt, ExcessStrategy, OutputGroup};
+
+/// Fee charged for `weight` weight units at `feerate`.
+pub fn calculate_fee(weight: u32, feerate: FeeRate) -> Amount {
+    feerate
+        .fee_wu(Weight::from_wu(u64::from(weight)))
+        .unwrap_or(Amount::ZERO)
+}
+
+/// Effective value of a group at `feerate`: its value minus the fee to spend
+/// it, in satoshis. May be negative for dust.
+pub fn effective_value(output: &OutputGroup, feerate: FeeRate) -> i64 {
+    output.value.to_sat() as i64 - calculate_fee(output.weight, feerate).to_sat() as i64
+}
+
+/// Waste metric for a selection. Lower is better.
+///
+/// Waste is the timing cost of spending the selected inputs now versus at the
+/// long-term fee rate, plus either the cost of the change output or the excess
+/// that is thrown away when no change is created. All arithmetic is checked and
+/// saturates at zero rather than underflowing.
+pub fn calculate_waste(
+    options: &CoinSelectionOpt,
+    selected_weight: u32,
+    accumulated_value: Amount,
+    estimated_fee: Amount,
+) -> Amount {
+    let long_term_feerate = options.long_term_feerate.unwrap_or(options.target_feerate);
+    let timing_cost = calculate_fee(selected_weight, options.target_feerate)
+        .checked_sub(calculate_fee(selected_weight, long_term_feerate))
+        .unwrap_or(Amount::ZERO);
+
+    let excess = accumulated_value
+        .checked_sub(options.target_value)
+        .and_then(|value| value.checked_sub(estimated_fee))
+        .unwrap_or(Amount::ZERO);
+    let change_or_excess = match options.excess_strategy {
+        ExcessStrategy::ToChange => options.change_cost,
+        ExcessStrategy::ToFee | ExcessStrategy::ToRecipient => excess,
+        // Sweep sends the whole balance to the recipient, so nothing is wasted
+        // beyond the timing cost of spending the inputs now.
+        ExcessStrategy::Sweep => Amount::ZERO,
+    };
+
+    timing_cost
+        .checked_add(change_or_excess)
+        .expect("waste metric overflowed Amount")
+}